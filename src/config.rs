@@ -0,0 +1,84 @@
+use serde::Deserialize;
+
+use crate::renderer::CellColor;
+
+const CONFIG_PATH: &str = "snake.json5";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub tick_ms: u64,
+    pub margin_divisor: u16,
+    pub food_count: usize,
+    pub glyph_range: (u32, u32),
+    pub snake_color: CellColor,
+    pub snake_head_color: CellColor,
+    pub food_color: CellColor,
+    pub wall_color: CellColor,
+    pub trail_color: CellColor,
+    pub trail_ticks: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            tick_ms: 50,
+            margin_divisor: 4,
+            food_count: 1,
+            glyph_range: (65382, 65437),
+            snake_color: CellColor::DarkGreen,
+            snake_head_color: CellColor::White,
+            food_color: CellColor::White,
+            wall_color: CellColor::Magenta,
+            trail_color: CellColor::DarkGray,
+            trail_ticks: 6,
+        }
+    }
+}
+
+impl Config {
+    pub fn load(cols: u16, rows: u16) -> Self {
+        std::fs::read_to_string(CONFIG_PATH)
+            .ok()
+            .and_then(|contents| json5::from_str(&contents).ok())
+            .unwrap_or_default()
+            .sanitized(cols, rows)
+    }
+
+    fn sanitized(mut self, cols: u16, rows: u16) -> Self {
+        let defaults = Config::default();
+        if self.glyph_range.0 > self.glyph_range.1 {
+            self.glyph_range = defaults.glyph_range;
+        }
+        self.trail_ticks = self.trail_ticks.min(254);
+
+        // margin_divisor must leave at least a 3-cell-wide interior (`to - from
+        // >= 3`) for `spawn_food_point`'s range to be non-empty; a bare floor on
+        // the divisor isn't enough, since e.g. margin_divisor=2 always collapses
+        // the arena to zero or one cell wide regardless of cols/rows.
+        let fits = |margin_divisor: u16| {
+            margin_divisor >= 2 && {
+                let margin_x = cols / margin_divisor;
+                let margin_y = rows / margin_divisor;
+                cols.saturating_sub(2 * margin_x) >= 3 && rows.saturating_sub(2 * margin_y) >= 3
+            }
+        };
+        if !fits(self.margin_divisor) {
+            self.margin_divisor = defaults.margin_divisor;
+        }
+
+        // Cap food_count to the arena's free-cell count at start (every interior
+        // cell but the snake's starting one) so the initial spawn_food_point
+        // calls can't spin forever looking for a cell that doesn't exist. The
+        // floor of one food item stays even on a one-cell interior, which has
+        // no free cell left once the snake is on it.
+        let margin_x = cols / self.margin_divisor;
+        let margin_y = rows / self.margin_divisor;
+        let interior_w = cols.saturating_sub(2 * margin_x).saturating_sub(2) as usize;
+        let interior_h = rows.saturating_sub(2 * margin_y).saturating_sub(2) as usize;
+        let free_cells = (interior_w * interior_h).saturating_sub(1);
+        self.food_count = self.food_count.clamp(1, free_cells.max(1));
+
+        self
+    }
+}
@@ -1,12 +1,31 @@
+#[cfg(not(feature = "windowed"))]
 use crossterm::{
-    cursor,
     event::{poll, read, Event, KeyCode},
-    queue,
-    style::{self, style, StyledContent, Stylize},
     terminal,
 };
 use rand::Rng;
-use std::{collections::HashSet, io::Write, time::Duration, vec};
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet},
+    vec,
+};
+#[cfg(not(feature = "windowed"))]
+use std::{
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+
+mod config;
+mod renderer;
+use config::Config;
+#[cfg(feature = "windowed")]
+use macroquad::prelude::{
+    get_frame_time, is_key_pressed, next_frame, screen_height, screen_width, KeyCode,
+};
+#[cfg(not(feature = "windowed"))]
+use renderer::TerminalRenderer;
+#[cfg(feature = "windowed")]
+use renderer::WindowedRenderer;
+use renderer::{CellColor, Renderer};
 
 trait CollisionDetector {
     fn has_collision(&self, point: &(u16, u16)) -> bool;
@@ -47,6 +66,16 @@ impl LookupPointQueue {
             Some(p)
         })
     }
+
+    fn remove(&mut self, point: &(u16, u16)) -> bool {
+        if let Some(ix) = self.vec.iter().position(|p| p == point) {
+            self.vec.remove(ix);
+            self.hash.remove(point);
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl Iterator for LookupPointQueue {
@@ -104,44 +133,91 @@ impl CollisionDetector for FoodState {
     }
 }
 
-struct ScreenPrinter {
-    output: std::io::Stdout,
+struct Trail {
+    markers: Vec<((u16, u16), u8)>,
 }
 
-impl ScreenPrinter {
+impl Trail {
     fn new() -> Self {
-        ScreenPrinter {
-            output: std::io::stdout(),
+        Trail {
+            markers: Vec::new(),
         }
     }
 
-    fn setup(&mut self) -> Result<(), std::io::Error> {
-        terminal::enable_raw_mode()?;
-        queue!(self.output, cursor::Hide)?;
-        Ok(())
+    fn deposit(&mut self, point: (u16, u16), strength: u8) {
+        self.markers.push((point, strength));
+    }
+
+    fn tick(&mut self) {
+        for marker in self.markers.iter_mut() {
+            marker.1 = marker.1.saturating_sub(1);
+        }
+        self.markers.retain(|(_, strength)| *strength > 0);
     }
+}
 
-    fn clear(&mut self) -> Result<(), std::io::Error> {
-        terminal::disable_raw_mode()?;
-        queue!(
-            self.output,
-            terminal::Clear(terminal::ClearType::All),
-            cursor::Show,
-            cursor::MoveTo(0, 0)
-        )?;
-        Ok(())
+fn spawn_food_point(
+    area: &GameArea,
+    snake: &SnakeState,
+    food: &FoodState,
+    rng: &mut impl Rng,
+) -> (u16, u16) {
+    loop {
+        let candidate = (
+            rng.gen_range((area.from.0 + 1)..(area.to.0 - 1)),
+            rng.gen_range((area.from.1 + 1)..(area.to.1 - 1)),
+        );
+        if !snake.lq.hash.contains(&candidate)
+            && !food.lq.hash.contains(&candidate)
+            && !area.has_collision(&candidate)
+        {
+            return candidate;
+        }
     }
 }
 
-fn get_next_point(point: &(u16, u16), direction: &Direction) -> (u16, u16) {
-    match direction {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WallMode {
+    Solid,
+    Wrap,
+}
+
+fn get_next_point(
+    point: &(u16, u16),
+    direction: &Direction,
+    area: &GameArea,
+    wall_mode: &WallMode,
+) -> (u16, u16) {
+    let raw = match direction {
         Direction::RIGHT => (point.0.saturating_add(1), point.1),
         Direction::LEFT => (point.0.saturating_sub(1), point.1),
         Direction::DOWN => (point.0, point.1.saturating_add(1)),
         Direction::UP => (point.0, point.1.saturating_sub(1)),
+    };
+
+    match wall_mode {
+        WallMode::Solid => raw,
+        WallMode::Wrap => {
+            let x = if raw.0 <= area.from.0 {
+                area.to.0 - 1
+            } else if raw.0 >= area.to.0 {
+                area.from.0 + 1
+            } else {
+                raw.0
+            };
+            let y = if raw.1 <= area.from.1 {
+                area.to.1 - 1
+            } else if raw.1 >= area.to.1 {
+                area.from.1 + 1
+            } else {
+                raw.1
+            };
+            (x, y)
+        }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     UP,
     DOWN,
@@ -149,107 +225,580 @@ enum Direction {
     RIGHT,
 }
 
+impl Direction {
+    fn is_opposite(&self, other: &Direction) -> bool {
+        matches!(
+            (self, other),
+            (Direction::UP, Direction::DOWN)
+                | (Direction::DOWN, Direction::UP)
+                | (Direction::LEFT, Direction::RIGHT)
+                | (Direction::RIGHT, Direction::LEFT)
+        )
+    }
+}
+
+#[cfg(not(feature = "windowed"))]
+struct InputQueue {
+    rx: Receiver<KeyCode>,
+}
+
+#[cfg(not(feature = "windowed"))]
+impl InputQueue {
+    /// Spawns a dedicated thread that blocks on `crossterm` events and forwards
+    /// key codes over a channel, decoupling input latency from render cadence.
+    fn spawn() -> Self {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || loop {
+            match poll(Duration::from_millis(50)) {
+                Ok(true) => {
+                    if let Ok(Event::Key(key)) = read() {
+                        if tx.send(key.code).is_err() {
+                            break;
+                        }
+                    }
+                }
+                Ok(false) => continue,
+                Err(_) => break,
+            }
+        });
+        Self { rx }
+    }
+
+    fn next(&self) -> Option<KeyCode> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Drains every pending key this tick, keeping only the most recent one.
+    fn last(&self) -> Option<KeyCode> {
+        let mut last = None;
+        while let Some(code) = self.next() {
+            last = Some(code);
+        }
+        last
+    }
+}
+
+fn manhattan(a: (u16, u16), b: (u16, u16)) -> u32 {
+    (a.0 as i32 - b.0 as i32).unsigned_abs() + (a.1 as i32 - b.1 as i32).unsigned_abs()
+}
+
+fn point_to_direction(from: (u16, u16), to: (u16, u16)) -> Option<Direction> {
+    match (to.0 as i32 - from.0 as i32, to.1 as i32 - from.1 as i32) {
+        (1, 0) => Some(Direction::RIGHT),
+        (-1, 0) => Some(Direction::LEFT),
+        (0, 1) => Some(Direction::DOWN),
+        (0, -1) => Some(Direction::UP),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct AStarNode {
+    point: (u16, u16),
+    f: u32,
+}
+
+impl Ord for AStarNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.f.cmp(&self.f)
+    }
+}
+
+impl PartialOrd for AStarNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn reconstruct_path(
+    came_from: &HashMap<(u16, u16), (u16, u16)>,
+    mut current: (u16, u16),
+) -> Vec<(u16, u16)> {
+    let mut path = vec![current];
+    while let Some(prev) = came_from.get(&current) {
+        path.push(*prev);
+        current = *prev;
+    }
+    path.reverse();
+    path
+}
+
+fn find_path(
+    start: (u16, u16),
+    goal: (u16, u16),
+    area: &GameArea,
+    obstacles: &HashSet<(u16, u16)>,
+    wall_mode: &WallMode,
+) -> Option<Vec<(u16, u16)>> {
+    let mut open = BinaryHeap::new();
+    let mut g_score: HashMap<(u16, u16), u32> = HashMap::new();
+    let mut came_from: HashMap<(u16, u16), (u16, u16)> = HashMap::new();
+    let mut closed: HashSet<(u16, u16)> = HashSet::new();
+
+    g_score.insert(start, 0);
+    open.push(AStarNode {
+        point: start,
+        f: manhattan(start, goal),
+    });
+
+    while let Some(AStarNode { point, .. }) = open.pop() {
+        if point == goal {
+            return Some(reconstruct_path(&came_from, point));
+        }
+        if !closed.insert(point) {
+            continue;
+        }
+        let g = *g_score.get(&point).unwrap_or(&u32::MAX);
+        for direction in [
+            Direction::UP,
+            Direction::DOWN,
+            Direction::LEFT,
+            Direction::RIGHT,
+        ] {
+            let next = get_next_point(&point, &direction, area, wall_mode);
+            if closed.contains(&next) || obstacles.contains(&next) || area.has_collision(&next) {
+                continue;
+            }
+            let tentative_g = g + 1;
+            if tentative_g < *g_score.get(&next).unwrap_or(&u32::MAX) {
+                came_from.insert(next, point);
+                g_score.insert(next, tentative_g);
+                open.push(AStarNode {
+                    point: next,
+                    f: tentative_g + manhattan(next, goal),
+                });
+            }
+        }
+    }
+    None
+}
+
+fn flood_fill_count(
+    start: (u16, u16),
+    area: &GameArea,
+    obstacles: &HashSet<(u16, u16)>,
+    wall_mode: &WallMode,
+    limit: usize,
+) -> usize {
+    let mut visited = HashSet::new();
+    visited.insert(start);
+    let mut stack = vec![start];
+    while let Some(point) = stack.pop() {
+        if visited.len() >= limit {
+            break;
+        }
+        for direction in [
+            Direction::UP,
+            Direction::DOWN,
+            Direction::LEFT,
+            Direction::RIGHT,
+        ] {
+            let next = get_next_point(&point, &direction, area, wall_mode);
+            if visited.contains(&next) || obstacles.contains(&next) || area.has_collision(&next) {
+                continue;
+            }
+            visited.insert(next);
+            stack.push(next);
+        }
+    }
+    visited.len()
+}
+
+fn autopilot_direction(
+    snake: &SnakeState,
+    food: &FoodState,
+    area: &GameArea,
+    current: &Direction,
+    wall_mode: &WallMode,
+) -> Option<Direction> {
+    let head = *snake.lq.head()?;
+    let goal = *food
+        .lq
+        .vec
+        .iter()
+        .min_by_key(|point| manhattan(head, **point))?;
+    let mut obstacles = snake.lq.hash.clone();
+    obstacles.remove(&head);
+
+    if let Some(path) = find_path(head, goal, area, &obstacles, wall_mode) {
+        if path.len() >= 2 {
+            if let Some(direction) = point_to_direction(head, path[1]) {
+                if !direction.is_opposite(current) {
+                    return Some(direction);
+                }
+            }
+        }
+    }
+
+    [
+        Direction::UP,
+        Direction::DOWN,
+        Direction::LEFT,
+        Direction::RIGHT,
+    ]
+    .into_iter()
+    .filter(|direction| !direction.is_opposite(current))
+    .map(|direction| {
+        (
+            direction,
+            get_next_point(&head, &direction, area, wall_mode),
+        )
+    })
+    .filter(|(_, next)| !obstacles.contains(next) && !area.has_collision(next))
+    .max_by_key(|(_, next)| flood_fill_count(*next, area, &obstacles, wall_mode, 64))
+    .map(|(direction, _)| direction)
+}
+
+#[cfg(not(feature = "windowed"))]
 fn main() -> Result<(), std::io::Error> {
+    let (cols, rows) = terminal::size()?;
+    let config = Config::load(cols, rows);
     let mut rng = rand::thread_rng();
-    let japanese_vec: Vec<char> = (65382..=65437)
-        .collect::<Vec<u32>>()
-        .iter()
-        .map(|n| std::char::from_u32(*n).unwrap_or(' '))
+    let (glyph_lo, glyph_hi) = config.glyph_range;
+    let japanese_vec: Vec<char> = (glyph_lo..=glyph_hi)
+        .map(|n| std::char::from_u32(n).unwrap_or(' '))
         .collect();
-    let (cols, rows) = terminal::size()?;
-    let mut screen = ScreenPrinter::new();
+    let mut renderer: Box<dyn Renderer> = Box::new(TerminalRenderer::new());
+    let margin_x = cols / config.margin_divisor;
+    let margin_y = rows / config.margin_divisor;
     let area = GameArea {
-        from: (cols / 4, rows / 4),
-        to: (3 * cols / 4, 3 * rows / 4),
+        from: (margin_x, margin_y),
+        to: (cols - margin_x, rows - margin_y),
     };
     let area_vec: Vec<(u16, u16)> = area.clone().into();
     let mut snake = SnakeState {
         lq: LookupPointQueue::new(&vec![(area.from.0 + 1, area.from.1 + 1)]),
     };
-    let initial_food_point = (
-        rng.gen_range((area.from.0 + 1)..(area.to.0 - 1)),
-        rng.gen_range((area.from.1 + 1)..(area.to.1 - 1)),
-    );
     let mut food = FoodState {
-        lq: LookupPointQueue::new(&vec![initial_food_point]),
+        lq: LookupPointQueue::new(&vec![]),
     };
+    for _ in 0..config.food_count {
+        let point = spawn_food_point(&area, &snake, &food, &mut rng);
+        food.lq.push(&point);
+    }
+    let mut trail = Trail::new();
     let mut direction: Direction = Direction::DOWN;
+    // `--wrap` starts in toroidal mode; the 'w' key toggles it at any time.
+    let mut wall_mode = if std::env::args().any(|arg| arg == "--wrap") {
+        WallMode::Wrap
+    } else {
+        WallMode::Solid
+    };
+    let input = InputQueue::spawn();
+    // `--ai` starts in autopilot; the 'a' key toggles it at any time.
+    let mut ai_enabled = std::env::args().any(|arg| arg == "--ai");
 
-    screen.setup()?;
+    renderer.setup()?;
     loop {
-        queue!(screen.output, terminal::Clear(terminal::ClearType::All))?;
-
-        let s = snake
-            .lq
-            .clone()
-            .into_iter()
-            .enumerate()
-            .map(|(ix, point)| {
-                let mut chr = japanese_vec[rng.gen_range(0..japanese_vec.len())]
-                    .with(style::Color::DarkGreen)
-                    .attribute(style::Attribute::Bold);
-                if ix == snake.lq.vec.len() - 1 {
-                    chr = chr.with(style::Color::White);
-                }
-                (point.0, point.1, chr)
-            })
-            .chain(
-                area_vec
-                    .clone()
-                    .into_iter()
-                    .map(|point| (point.0, point.1, ' '.on_magenta())),
-            )
-            .chain(food.lq.clone().into_iter().map(|point| {
-                (
-                    point.0,
-                    point.1,
-                    '$'.with(style::Color::White)
-                        .attribute(style::Attribute::Bold),
-                )
-            }));
-
-        for ent in s.collect::<Vec<(u16, u16, StyledContent<char>)>>() {
-            queue!(
-                screen.output,
-                cursor::MoveTo(ent.0, ent.1),
-                style::PrintStyledContent(ent.2)
-            )?;
-        }
-
-        let next_point = get_next_point(snake.lq.head().unwrap_or(&area.from), &direction);
+        trail.tick();
+        renderer.clear()?;
+
+        let snake_len = snake.lq.vec.len();
+        for (ix, point) in snake.lq.clone().into_iter().enumerate() {
+            let glyph = japanese_vec[rng.gen_range(0..japanese_vec.len())];
+            let fg = if ix == snake_len - 1 {
+                config.snake_head_color
+            } else {
+                config.snake_color
+            };
+            renderer.draw_cell(point.0, point.1, glyph, fg, CellColor::Black, true);
+        }
+        for point in area_vec.clone() {
+            renderer.draw_cell(
+                point.0,
+                point.1,
+                ' ',
+                CellColor::Black,
+                config.wall_color,
+                false,
+            );
+        }
+        for point in food.lq.clone() {
+            renderer.draw_cell(
+                point.0,
+                point.1,
+                '$',
+                config.food_color,
+                CellColor::Black,
+                true,
+            );
+        }
+        for (point, strength) in trail.markers.clone() {
+            renderer.draw_cell(
+                point.0,
+                point.1,
+                '.',
+                config.trail_color,
+                CellColor::Black,
+                strength == config.trail_ticks,
+            );
+        }
+
+        let next_point = get_next_point(
+            snake.lq.head().unwrap_or(&area.from),
+            &direction,
+            &area,
+            &wall_mode,
+        );
         if snake.has_collision(&next_point) {
             break;
         }
-        if area.has_collision(&next_point) {
+        if wall_mode == WallMode::Solid && area.has_collision(&next_point) {
             break;
         }
         snake.lq.push(&next_point);
         if food.has_collision(&next_point) {
-            food.lq.pop();
-            food.lq.push(&(
-                rng.gen_range((area.from.0 + 1)..(area.to.0 - 1)),
-                rng.gen_range((area.from.1 + 1)..(area.to.1 - 1)),
-            ))
+            food.lq.remove(&next_point);
+            // Deposited one tick "hot": the top-of-loop `trail.tick()` decrements it
+            // to `trail_ticks` before it is ever drawn, so it still renders at full
+            // strength (bold) on its first frame.
+            trail.deposit(next_point, config.trail_ticks + 1);
+            let spawn_point = spawn_food_point(&area, &snake, &food, &mut rng);
+            food.lq.push(&spawn_point);
         } else {
             snake.lq.pop();
         }
 
-        queue!(screen.output, cursor::MoveTo(cols, rows))?;
-        screen.output.flush()?;
+        renderer.present()?;
 
-        if poll(Duration::from_millis(300))? {
-            if let Event::Key(key) = read()? {
-                match key.code {
-                    KeyCode::Up => direction = Direction::UP,
-                    KeyCode::Down => direction = Direction::DOWN,
-                    KeyCode::Right => direction = Direction::RIGHT,
-                    KeyCode::Left => direction = Direction::LEFT,
-                    _ => {}
+        if let Some(code) = input.last() {
+            if code == KeyCode::Char('a') {
+                ai_enabled = !ai_enabled;
+            }
+            if code == KeyCode::Char('w') {
+                wall_mode = match wall_mode {
+                    WallMode::Solid => WallMode::Wrap,
+                    WallMode::Wrap => WallMode::Solid,
+                };
+            }
+            let requested = match code {
+                KeyCode::Up => Some(Direction::UP),
+                KeyCode::Down => Some(Direction::DOWN),
+                KeyCode::Right => Some(Direction::RIGHT),
+                KeyCode::Left => Some(Direction::LEFT),
+                _ => None,
+            };
+            if let Some(requested) = requested {
+                if !requested.is_opposite(&direction) {
+                    direction = requested;
                 }
+                ai_enabled = false;
             }
         }
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        if ai_enabled {
+            if let Some(requested) =
+                autopilot_direction(&snake, &food, &area, &direction, &wall_mode)
+            {
+                direction = requested;
+            }
+        }
+        std::thread::sleep(std::time::Duration::from_millis(config.tick_ms));
     }
-    screen.clear()?;
+    renderer.teardown()?;
     Ok(())
 }
+
+#[cfg(feature = "windowed")]
+#[macroquad::main("Matrix Snake")]
+async fn main() {
+    let cols = (screen_width() / renderer::CELL_SIZE) as u16;
+    let rows = (screen_height() / renderer::CELL_SIZE) as u16;
+    let config = Config::load(cols, rows);
+    let mut rng = rand::thread_rng();
+    let (glyph_lo, glyph_hi) = config.glyph_range;
+    let japanese_vec: Vec<char> = (glyph_lo..=glyph_hi)
+        .map(|n| std::char::from_u32(n).unwrap_or(' '))
+        .collect();
+    let mut renderer: Box<dyn Renderer> = Box::new(WindowedRenderer::new());
+    let margin_x = cols / config.margin_divisor;
+    let margin_y = rows / config.margin_divisor;
+    let area = GameArea {
+        from: (margin_x, margin_y),
+        to: (cols - margin_x, rows - margin_y),
+    };
+    let area_vec: Vec<(u16, u16)> = area.clone().into();
+    let mut snake = SnakeState {
+        lq: LookupPointQueue::new(&vec![(area.from.0 + 1, area.from.1 + 1)]),
+    };
+    let mut food = FoodState {
+        lq: LookupPointQueue::new(&vec![]),
+    };
+    for _ in 0..config.food_count {
+        let point = spawn_food_point(&area, &snake, &food, &mut rng);
+        food.lq.push(&point);
+    }
+    let mut trail = Trail::new();
+    let mut direction: Direction = Direction::DOWN;
+    let mut wall_mode = WallMode::Solid;
+    let mut ai_enabled = false;
+    // Frame rate and tick rate are decoupled: we redraw and read keys every
+    // frame but only step the game (and the autopilot below) once `tick_secs`
+    // has accumulated, mirroring the terminal build's `tick_ms` sleep.
+    let tick_secs = config.tick_ms as f32 / 1000.0;
+    let mut tick_timer: f32 = 0.0;
+
+    let _ = renderer.setup();
+    loop {
+        if is_key_pressed(KeyCode::A) {
+            ai_enabled = !ai_enabled;
+        }
+        if is_key_pressed(KeyCode::W) {
+            wall_mode = match wall_mode {
+                WallMode::Solid => WallMode::Wrap,
+                WallMode::Wrap => WallMode::Solid,
+            };
+        }
+        let requested = if is_key_pressed(KeyCode::Up) {
+            Some(Direction::UP)
+        } else if is_key_pressed(KeyCode::Down) {
+            Some(Direction::DOWN)
+        } else if is_key_pressed(KeyCode::Right) {
+            Some(Direction::RIGHT)
+        } else if is_key_pressed(KeyCode::Left) {
+            Some(Direction::LEFT)
+        } else {
+            None
+        };
+        if let Some(requested) = requested {
+            if !requested.is_opposite(&direction) {
+                direction = requested;
+            }
+            ai_enabled = false;
+        }
+
+        tick_timer += get_frame_time();
+        if tick_timer >= tick_secs {
+            tick_timer -= tick_secs;
+            trail.tick();
+
+            if ai_enabled {
+                if let Some(requested) =
+                    autopilot_direction(&snake, &food, &area, &direction, &wall_mode)
+                {
+                    direction = requested;
+                }
+            }
+
+            let next_point = get_next_point(
+                snake.lq.head().unwrap_or(&area.from),
+                &direction,
+                &area,
+                &wall_mode,
+            );
+            if snake.has_collision(&next_point)
+                || (wall_mode == WallMode::Solid && area.has_collision(&next_point))
+            {
+                break;
+            }
+            snake.lq.push(&next_point);
+            if food.has_collision(&next_point) {
+                food.lq.remove(&next_point);
+                // Unlike the terminal `main`, this tick's `trail.tick()` has already
+                // run and the frame is drawn right after, so the deposit starts at
+                // `trail_ticks` and renders at full strength (bold) on its first frame.
+                trail.deposit(next_point, config.trail_ticks);
+                let spawn_point = spawn_food_point(&area, &snake, &food, &mut rng);
+                food.lq.push(&spawn_point);
+            } else {
+                snake.lq.pop();
+            }
+        }
+
+        let _ = renderer.clear();
+        let snake_len = snake.lq.vec.len();
+        for (ix, point) in snake.lq.clone().into_iter().enumerate() {
+            let glyph = japanese_vec[rng.gen_range(0..japanese_vec.len())];
+            let fg = if ix == snake_len - 1 {
+                config.snake_head_color
+            } else {
+                config.snake_color
+            };
+            renderer.draw_cell(point.0, point.1, glyph, fg, CellColor::Black, true);
+        }
+        for point in area_vec.clone() {
+            renderer.draw_cell(
+                point.0,
+                point.1,
+                ' ',
+                CellColor::Black,
+                config.wall_color,
+                false,
+            );
+        }
+        for point in food.lq.clone() {
+            renderer.draw_cell(
+                point.0,
+                point.1,
+                '$',
+                config.food_color,
+                CellColor::Black,
+                true,
+            );
+        }
+        for (point, strength) in trail.markers.clone() {
+            renderer.draw_cell(
+                point.0,
+                point.1,
+                '.',
+                config.trail_color,
+                CellColor::Black,
+                strength == config.trail_ticks,
+            );
+        }
+        let _ = renderer.present();
+
+        next_frame().await;
+    }
+    let _ = renderer.teardown();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_area() -> GameArea {
+        GameArea {
+            from: (0, 0),
+            to: (9, 9),
+        }
+    }
+
+    #[test]
+    fn find_path_in_open_space_takes_the_shortest_first_step() {
+        let area = test_area();
+        let obstacles = HashSet::new();
+        let path = find_path((2, 2), (2, 5), &area, &obstacles, &WallMode::Solid)
+            .expect("food should be reachable");
+        assert_eq!(path.first(), Some(&(2, 2)));
+        assert_eq!(path.last(), Some(&(2, 5)));
+        assert_eq!(point_to_direction(path[0], path[1]), Some(Direction::DOWN));
+    }
+
+    #[test]
+    fn autopilot_falls_back_to_survival_move_when_food_is_walled_off() {
+        let area = test_area();
+        let head = (4, 4);
+        let wall: Vec<(u16, u16)> = (1..=8).map(|x| (x, 5)).collect();
+
+        // A full-width wall at y = 5 cuts the head off from the food below it.
+        let mut obstacles: HashSet<(u16, u16)> = wall.iter().cloned().collect();
+        assert!(find_path(head, (4, 8), &area, &obstacles, &WallMode::Solid).is_none());
+        obstacles.remove(&head);
+
+        let mut body = vec![head];
+        body.extend(wall.iter().cloned());
+        let snake = SnakeState {
+            lq: LookupPointQueue::new(&body),
+        };
+        let food = FoodState {
+            lq: LookupPointQueue::new(&vec![(4, 8)]),
+        };
+
+        let direction =
+            autopilot_direction(&snake, &food, &area, &Direction::DOWN, &WallMode::Solid)
+                .expect("should pick a survival move instead of giving up");
+        // DOWN is walled off and UP is rejected as a reversal of the current
+        // direction, so the survival move must sidestep left or right.
+        assert!(matches!(direction, Direction::LEFT | Direction::RIGHT));
+    }
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub enum CellColor {
+    Black,
+    White,
+    DarkGreen,
+    Magenta,
+    DarkGray,
+}
+
+impl Default for CellColor {
+    fn default() -> Self {
+        CellColor::Black
+    }
+}
+
+pub trait Renderer {
+    fn setup(&mut self) -> std::io::Result<()>;
+    fn clear(&mut self) -> std::io::Result<()>;
+    fn draw_cell(&mut self, x: u16, y: u16, glyph: char, fg: CellColor, bg: CellColor, bold: bool);
+    fn present(&mut self) -> std::io::Result<()>;
+    fn teardown(&mut self) -> std::io::Result<()>;
+}
+
+mod terminal_renderer;
+pub use terminal_renderer::TerminalRenderer;
+
+#[cfg(feature = "windowed")]
+mod windowed_renderer;
+#[cfg(feature = "windowed")]
+pub use windowed_renderer::WindowedRenderer;
+#[cfg(feature = "windowed")]
+pub(crate) use windowed_renderer::CELL_SIZE;
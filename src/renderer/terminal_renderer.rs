@@ -0,0 +1,63 @@
+use crossterm::{cursor, queue, style, style::Stylize, terminal};
+use std::io::Write;
+
+use super::{CellColor, Renderer};
+
+fn to_crossterm(color: CellColor) -> style::Color {
+    match color {
+        CellColor::Black => style::Color::Black,
+        CellColor::White => style::Color::White,
+        CellColor::DarkGreen => style::Color::DarkGreen,
+        CellColor::Magenta => style::Color::Magenta,
+        CellColor::DarkGray => style::Color::DarkGrey,
+    }
+}
+
+pub struct TerminalRenderer {
+    output: std::io::Stdout,
+}
+
+impl TerminalRenderer {
+    pub fn new() -> Self {
+        TerminalRenderer {
+            output: std::io::stdout(),
+        }
+    }
+}
+
+impl Renderer for TerminalRenderer {
+    fn setup(&mut self) -> std::io::Result<()> {
+        terminal::enable_raw_mode()?;
+        queue!(self.output, cursor::Hide)
+    }
+
+    fn clear(&mut self) -> std::io::Result<()> {
+        queue!(self.output, terminal::Clear(terminal::ClearType::All))
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, glyph: char, fg: CellColor, bg: CellColor, bold: bool) {
+        let mut styled = glyph.with(to_crossterm(fg)).on(to_crossterm(bg));
+        if bold {
+            styled = styled.attribute(style::Attribute::Bold);
+        }
+        let _ = queue!(
+            self.output,
+            cursor::MoveTo(x, y),
+            style::PrintStyledContent(styled)
+        );
+    }
+
+    fn present(&mut self) -> std::io::Result<()> {
+        self.output.flush()
+    }
+
+    fn teardown(&mut self) -> std::io::Result<()> {
+        terminal::disable_raw_mode()?;
+        queue!(
+            self.output,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::Show,
+            cursor::MoveTo(0, 0)
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use macroquad::prelude::*;
+
+use super::{CellColor, Renderer};
+
+pub(crate) const CELL_SIZE: f32 = 18.0;
+
+fn to_macroquad(color: CellColor) -> Color {
+    match color {
+        CellColor::Black => BLACK,
+        CellColor::White => WHITE,
+        CellColor::DarkGreen => DARKGREEN,
+        CellColor::Magenta => MAGENTA,
+        CellColor::DarkGray => DARKGRAY,
+    }
+}
+
+pub struct WindowedRenderer;
+
+impl WindowedRenderer {
+    pub fn new() -> Self {
+        WindowedRenderer
+    }
+}
+
+impl Renderer for WindowedRenderer {
+    fn setup(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn clear(&mut self) -> std::io::Result<()> {
+        clear_background(BLACK);
+        Ok(())
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, glyph: char, fg: CellColor, bg: CellColor, bold: bool) {
+        let px = x as f32 * CELL_SIZE;
+        let py = y as f32 * CELL_SIZE;
+        draw_rectangle(px, py, CELL_SIZE, CELL_SIZE, to_macroquad(bg));
+        let font_size = if bold { CELL_SIZE * 1.1 } else { CELL_SIZE };
+        draw_text(
+            &glyph.to_string(),
+            px,
+            py + CELL_SIZE,
+            font_size,
+            to_macroquad(fg),
+        );
+    }
+
+    fn present(&mut self) -> std::io::Result<()> {
+        // The actual buffer swap happens via `next_frame().await` in the
+        // windowed entry point's async loop (see `main`'s `#[macroquad::main]`
+        // function); macroquad's draw_* calls above already submit to the
+        // current frame, so there is nothing left to flush here.
+        Ok(())
+    }
+
+    fn teardown(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}